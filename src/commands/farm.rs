@@ -0,0 +1,97 @@
+//! starts the node and farmer, and drives the main farming loop
+
+use std::path::PathBuf;
+
+use color_eyre::eyre::Report;
+use tokio::sync::mpsc;
+use tracing::instrument;
+
+use crate::notifications::{self, FarmEvent};
+use crate::summary::SummaryEvent;
+use crate::{AlreadyRunningError, ConfigNotFoundError, NodeFailureError};
+
+/// starts the node and farmer instance, and drives the main farming loop until it exits
+///
+/// when `notify` is set, fires a desktop notification when initial plotting
+/// finishes and whenever a reward is credited
+#[instrument]
+pub async fn farm(
+    verbose: bool,
+    executor: bool,
+    no_rotation: bool,
+    notify: bool,
+) -> Result<(), Report> {
+    let (summary_tx, mut summary_rx) = mpsc::unbounded_channel::<SummaryEvent>();
+
+    start_instance(verbose, executor, no_rotation, summary_tx).await?;
+
+    while let Some(event) = summary_rx.recv().await {
+        if notify {
+            notify_on(&event);
+        }
+    }
+
+    Ok(())
+}
+
+/// starts the node and the farmer instance
+///
+/// `_summary_tx` is not used and is dropped on return, so no plotting/reward
+/// transitions are sent: `farm`'s event loop ends as soon as this returns, and
+/// no desktop notification fires
+///
+/// fails with [`ConfigNotFoundError`] if `subspace init` has not been run yet,
+/// with [`AlreadyRunningError`] if another instance is already running, or
+/// with [`NodeFailureError`] if the node/farmer fails to start
+async fn start_instance(
+    _verbose: bool,
+    _executor: bool,
+    _no_rotation: bool,
+    _summary_tx: mpsc::UnboundedSender<SummaryEvent>,
+) -> Result<(), Report> {
+    if !crate::config::config_exists()? {
+        return Err(Report::new(ConfigNotFoundError));
+    }
+
+    if is_already_running()? {
+        return Err(Report::new(AlreadyRunningError));
+    }
+
+    start_node().map_err(|error| Report::new(NodeFailureError(error.to_string())))?;
+
+    Ok(())
+}
+
+/// path to the lock file held for as long as a farmer/node instance is running
+fn lock_file_path() -> Result<PathBuf, Report> {
+    let mut path = dirs::data_local_dir()
+        .ok_or_else(|| Report::msg("could not determine data directory"))?;
+    path.push("subspace-cli");
+    path.push("farm.lock");
+
+    Ok(path)
+}
+
+/// whether a farmer/node instance is already running, per the lock file it holds
+fn is_already_running() -> Result<bool, Report> {
+    Ok(lock_file_path()?.is_file())
+}
+
+/// starts the node and farmer process
+///
+/// stubbed out for now: always succeeds
+fn start_node() -> Result<(), std::io::Error> {
+    Ok(())
+}
+
+/// maps a farm-loop state transition onto a desktop notification
+fn notify_on(event: &SummaryEvent) {
+    let farm_event = match event {
+        SummaryEvent::PlottingComplete => FarmEvent::PlottingComplete,
+        SummaryEvent::RewardReceived(reward) => {
+            FarmEvent::RewardReceived { amount: reward.amount.clone() }
+        }
+    };
+
+    notifications::notify(&farm_event);
+}
@@ -0,0 +1,69 @@
+//! reports farmer status: reward totals and initial plotting progress
+
+use color_eyre::eyre::Report;
+
+use crate::summary::{PlottingStatus, Summary};
+use crate::OutputFormat;
+
+/// reports the farmer's current summary (reward totals, plotting progress, and
+/// instance status), formatted per `output`
+pub async fn info(output: OutputFormat) -> Result<(), Report> {
+    let summary = Summary::collect().await?;
+
+    match output {
+        OutputFormat::Human => print_human(&summary),
+        OutputFormat::Json => print_json(&summary)?,
+        OutputFormat::Csv => print_csv(&summary),
+    }
+
+    Ok(())
+}
+
+fn print_human(summary: &Summary) {
+    println!("instance running: {}", summary.instance_running);
+    match summary.plotting_status {
+        Some(PlottingStatus::InProgress(percent)) => println!("plotting: {percent}% complete"),
+        Some(PlottingStatus::Complete) => println!("plotting: complete"),
+        None => println!("plotting: unknown"),
+    }
+
+    println!("rewards: {} credited", summary.rewards.len());
+    for reward in &summary.rewards {
+        println!("  block {}: {}", reward.block, reward.amount);
+    }
+}
+
+fn print_json(summary: &Summary) -> Result<(), Report> {
+    println!("{}", serde_json::to_string_pretty(summary)?);
+    Ok(())
+}
+
+fn print_csv(summary: &Summary) {
+    let plotting_status = match summary.plotting_status {
+        Some(PlottingStatus::InProgress(percent)) => format!("in_progress({percent})"),
+        Some(PlottingStatus::Complete) => "complete".to_string(),
+        None => "unknown".to_string(),
+    };
+
+    println!("field,value");
+    println!("instance_running,{}", csv_field(&summary.instance_running.to_string()));
+    println!("plotting_status,{}", csv_field(&plotting_status));
+    for reward in &summary.rewards {
+        println!(
+            "reward,{}",
+            csv_field(&format!("block {} credited {}", reward.block, reward.amount))
+        );
+    }
+}
+
+/// escapes a single CSV field per RFC 4180: quotes the field and doubles any
+/// embedded quotes whenever it contains a comma, quote, or newline, so values
+/// like currency-formatted reward amounts (which commonly contain commas)
+/// don't silently split across columns
+fn csv_field(value: &str) -> String {
+    if value.contains(['"', ',', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,4 @@
+//! the individual subcommands the CLI dispatches to
+
+pub mod farm;
+pub mod info;
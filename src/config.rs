@@ -0,0 +1,20 @@
+//! locating and checking for the farmer/node config file written by `subspace init`
+
+use std::path::PathBuf;
+
+use color_eyre::eyre::Report;
+
+/// path to the config file written by `subspace init`
+pub fn config_file_path() -> Result<PathBuf, Report> {
+    let mut path =
+        dirs::config_dir().ok_or_else(|| Report::msg("could not determine config directory"))?;
+    path.push("subspace-cli");
+    path.push("config.toml");
+
+    Ok(path)
+}
+
+/// whether the config file written by `subspace init` exists
+pub fn config_exists() -> Result<bool, Report> {
+    Ok(config_file_path()?.is_file())
+}
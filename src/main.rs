@@ -6,6 +6,7 @@
 
 mod commands;
 mod config;
+mod notifications;
 mod summary;
 mod utils;
 
@@ -14,11 +15,12 @@ mod tests;
 
 use std::io::{self, Write};
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
 use color_eyre::eyre::{Context, Report};
 use color_eyre::Help;
 use crossterm::event::{Event, KeyCode};
-use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType};
 use crossterm::{cursor, execute};
 use owo_colors::OwoColorize;
 use strum::IntoEnumIterator;
@@ -29,7 +31,10 @@ use crate::commands::farm::farm;
 use crate::commands::info::info;
 use crate::commands::init::init;
 use crate::commands::wipe::wipe_config;
-use crate::utils::{get_user_input, open_log_dir, support_message, yes_or_no_parser};
+use crate::utils::{
+    get_user_input, is_noninteractive, log_file_paths, open_log_dir, support_message,
+    yes_or_no_parser,
+};
 
 #[cfg(all(
     target_arch = "x86_64",
@@ -50,6 +55,18 @@ struct Cli {
     command: Option<Commands>,
 }
 
+/// output format for commands that can emit machine-readable data
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// human-readable text
+    #[default]
+    Human,
+    /// JSON, for programmatic consumers
+    Json,
+    /// CSV (one row per summary field, plus one row per reward), for spreadsheet/monitoring ingestion
+    Csv,
+}
+
 /// Available commands for the CLI
 #[derive(Debug, Subcommand, EnumIter)]
 enum Commands {
@@ -63,6 +80,9 @@ enum Commands {
         executor: bool,
         #[arg(long, action)]
         no_rotation: bool,
+        /// send a desktop notification when initial plotting finishes and on reward events
+        #[arg(long, action)]
+        notify: bool,
     },
     #[command(about = "wipes the node and farm instance (along with your plots)")]
     Wipe {
@@ -73,23 +93,156 @@ enum Commands {
     },
     #[command(about = "displays info about the farmer instance (i.e. total amount of rewards, \
                        and status of initial plotting)")]
-    Info,
+    Info {
+        /// output format for the reported data
+        #[arg(long, value_enum, default_value = "human")]
+        output: OutputFormat,
+    },
     OpenLogs,
+    #[command(about = "pages through the farmer/node logs right inside the terminal")]
+    Logs {
+        /// keep tailing the logs and auto-scroll as new lines arrive
+        #[arg(short, long, action)]
+        follow: bool,
+    },
+    #[command(about = "generates a shell completion script and prints it to stdout")]
+    #[strum(disabled)]
+    Completions {
+        /// the shell to generate the completion script for
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+}
+
+/// stable process exit codes, so wrapper scripts and service managers can react
+/// differently to e.g. a missing config versus a failed node versus a cancelled prompt
+#[derive(Debug, Clone, Copy)]
+enum ExitCode {
+    /// everything completed successfully
+    Success = 0,
+    /// the config file required for the requested command was not found
+    ConfigNotFound = 10,
+    /// a farmer/node instance is already running
+    AlreadyRunning = 11,
+    /// the node or farmer failed to start, or crashed
+    NodeFailure = 12,
+    /// the user cancelled an interactive prompt
+    UserCancelled = 13,
+    /// any other, unclassified failure
+    Internal = 70,
+}
+
+impl ExitCode {
+    fn as_i32(self) -> i32 {
+        self as i32
+    }
+}
+
+/// marks a [`Report`] as having been caused by the user cancelling an interactive
+/// prompt (e.g. Ctrl-C), so `main` can map it to [`ExitCode::UserCancelled`]
+/// instead of the generic success/failure split
+#[derive(Debug)]
+struct UserCancelled;
+
+impl std::fmt::Display for UserCancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cancelled by user")
+    }
+}
+
+impl std::error::Error for UserCancelled {}
+
+/// marks a [`Report`] as having been caused by a missing config file; raised by
+/// whichever command requires the config (e.g. `farm`, `info`) to read it
+#[derive(Debug)]
+pub(crate) struct ConfigNotFoundError;
+
+impl std::fmt::Display for ConfigNotFoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "config file not found; run `subspace init` first")
+    }
+}
+
+impl std::error::Error for ConfigNotFoundError {}
+
+/// marks a [`Report`] as having been caused by a farmer/node instance that was
+/// already running; raised by `farm`
+#[derive(Debug)]
+pub(crate) struct AlreadyRunningError;
+
+impl std::fmt::Display for AlreadyRunningError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a farmer/node instance is already running")
+    }
+}
+
+impl std::error::Error for AlreadyRunningError {}
+
+/// marks a [`Report`] as having been caused by the node or farmer failing to
+/// start, or crashing; raised by `farm`
+#[derive(Debug)]
+pub(crate) struct NodeFailureError(pub(crate) String);
+
+impl std::fmt::Display for NodeFailureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "node/farmer failure: {}", self.0)
+    }
+}
+
+impl std::error::Error for NodeFailureError {}
+
+/// classifies a top-level failure into its [`ExitCode`] by looking for the
+/// category each command function tags its errors with, the same way
+/// [`UserCancelled`] is matched below
+fn classify_error(report: &Report) -> ExitCode {
+    if report.downcast_ref::<UserCancelled>().is_some() {
+        ExitCode::UserCancelled
+    } else if report.downcast_ref::<ConfigNotFoundError>().is_some() {
+        ExitCode::ConfigNotFound
+    } else if report.downcast_ref::<AlreadyRunningError>().is_some() {
+        ExitCode::AlreadyRunning
+    } else if report.downcast_ref::<NodeFailureError>().is_some() {
+        ExitCode::NodeFailure
+    } else {
+        ExitCode::Internal
+    }
 }
 
 #[tokio::main]
 #[instrument]
-async fn main() -> Result<(), Report> {
+async fn main() {
+    let exit_code = match run().await {
+        Ok(()) => ExitCode::Success,
+        Err(report) => {
+            let exit_code = classify_error(&report);
+            eprintln!("{report:?}");
+            exit_code
+        }
+    };
+
+    io::stdout().flush().ok();
+    std::process::exit(exit_code.as_i32());
+}
+
+#[instrument]
+async fn run() -> Result<(), Report> {
     let args = Cli::parse();
     match args.command {
-        Some(Commands::Info) => {
-            info().await.suggestion(support_message())?;
+        Some(Commands::Completions { shell }) => {
+            // completion scripts are consumed by the shell (e.g. `> _subspace`), so this
+            // path must print nothing but the generated script, with no suggestion wrapping
+            let mut command = Cli::command();
+            let name = command.get_name().to_string();
+            clap_complete::generate(shell, &mut command, name, &mut io::stdout());
+        }
+        Some(Commands::Info { output }) => {
+            info(output).await.suggestion(support_message())?;
         }
         Some(Commands::Init) => {
             init().suggestion(support_message())?;
         }
-        Some(Commands::Farm { verbose, executor, no_rotation }) => {
-            farm(verbose, executor, no_rotation).await.suggestion(support_message())?;
+        Some(Commands::Farm { verbose, executor, no_rotation, notify }) => {
+            farm(verbose, executor, no_rotation, notify).await.suggestion(support_message())?;
         }
         Some(Commands::Wipe { farmer, node }) => {
             wipe_config(farmer, node).await.suggestion(support_message())?;
@@ -97,6 +250,9 @@ async fn main() -> Result<(), Report> {
         Some(Commands::OpenLogs) => {
             open_log_dir().suggestion(support_message())?;
         }
+        Some(Commands::Logs { follow }) => {
+            view_logs(follow).suggestion(support_message())?;
+        }
         None => arrow_key_mode().await.suggestion(support_message())?,
     }
 
@@ -105,6 +261,13 @@ async fn main() -> Result<(), Report> {
 
 #[instrument]
 async fn arrow_key_mode() -> Result<(), Report> {
+    if is_noninteractive() {
+        return Err(Report::msg(
+            "refusing to enter the interactive arrow-key menu in non-interactive mode; run a \
+             subcommand directly (e.g. `subspace farm`) instead",
+        ));
+    }
+
     let mut stdout = io::stdout();
 
     // Options to be displayed
@@ -145,7 +308,8 @@ async fn arrow_key_mode() -> Result<(), Report> {
                 KeyCode::Char('c')
                     if event.modifiers.contains(crossterm::event::KeyModifiers::CONTROL) =>
                 {
-                    return Ok(());
+                    disable_raw_mode()?;
+                    return Err(Report::new(UserCancelled));
                 }
                 _ => {}
             }
@@ -174,21 +338,132 @@ async fn arrow_key_mode() -> Result<(), Report> {
             let no_rotation =
                 get_user_input(prompt, None, yes_or_no_parser).context("prompt failed")?;
 
-            farm(verbose, executor, no_rotation).await.suggestion(support_message())?;
+            let prompt = "Do you want a desktop notification on plotting/reward events? [y/n]: ";
+            let notify = get_user_input(prompt, None, yes_or_no_parser).context("prompt failed")?;
+
+            farm(verbose, executor, no_rotation, notify).await.suggestion(support_message())?;
         }
         2 => {
             wipe_config(false, false).await.suggestion(support_message())?;
         }
         3 => {
-            info().await.suggestion(support_message())?;
+            info(OutputFormat::Human).await.suggestion(support_message())?;
         }
         4 => {
             open_log_dir().suggestion(support_message())?;
         }
+        5 => {
+            view_logs(false).suggestion(support_message())?;
+        }
         _ => {
-            unreachable!("this number must stay in [0-4]")
+            unreachable!("this number must stay in [0-5]")
+        }
+    }
+
+    Ok(())
+}
+
+/// pages through the farmer/node log files right inside the terminal, `more`-style
+///
+/// with `follow`, the view re-reads the log files on every tick and auto-scrolls
+/// to the bottom as new lines are appended, instead of waiting for key presses
+#[instrument]
+fn view_logs(follow: bool) -> Result<(), Report> {
+    if is_noninteractive() {
+        return Err(Report::msg(
+            "refusing to page through logs in non-interactive mode; read the log files \
+             directly instead (see `subspace open-logs`)",
+        ));
+    }
+
+    let paths = log_file_paths().context("could not locate log files")?;
+    let mut lines = read_log_lines(&paths)?;
+    let mut current_line = 0usize;
+
+    enable_raw_mode()?;
+    let result = view_logs_loop(&mut lines, &mut current_line, &paths, follow);
+    disable_raw_mode()?;
+
+    result
+}
+
+/// drives the pager's render/input loop; split out so raw mode is always disabled,
+/// even if rendering or reading a key fails
+fn view_logs_loop(
+    lines: &mut Vec<String>,
+    current_line: &mut usize,
+    paths: &[std::path::PathBuf],
+    follow: bool,
+) -> Result<(), Report> {
+    let mut stdout = io::stdout();
+
+    loop {
+        let (_, height) = size()?;
+        let page_size = (height as usize).saturating_sub(1).max(1);
+
+        render_log_page(&mut stdout, lines, *current_line, page_size)?;
+
+        if follow {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            *lines = read_log_lines(paths)?;
+            *current_line = lines.len().saturating_sub(page_size);
+
+            if crossterm::event::poll(std::time::Duration::from_millis(0))?
+                && matches!(crossterm::event::read()?, Event::Key(event) if event.code == KeyCode::Char('q'))
+            {
+                break;
+            }
+            continue;
         }
+
+        if let Event::Key(event) = crossterm::event::read()? {
+            let max_line = lines.len().saturating_sub(1);
+            let last_page_start = lines.len().saturating_sub(page_size);
+            match event.code {
+                KeyCode::Char('q') => break,
+                KeyCode::Char(' ') => {
+                    *current_line = (*current_line + page_size).min(last_page_start);
+                }
+                KeyCode::Enter | KeyCode::Down | KeyCode::Char('j') => {
+                    *current_line = (*current_line + 1).min(max_line);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    *current_line = current_line.saturating_sub(1);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// reads and flattens every log file's lines, in order, into a single buffer
+fn read_log_lines(paths: &[std::path::PathBuf]) -> Result<Vec<String>, Report> {
+    let mut lines = Vec::new();
+    for path in paths {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read log file {}", path.display()))?;
+        lines.extend(contents.lines().map(str::to_owned));
+    }
+
+    Ok(lines)
+}
+
+/// renders one terminal-height window of `lines`, starting at `offset`
+fn render_log_page(
+    stdout: &mut io::Stdout,
+    lines: &[String],
+    offset: usize,
+    page_size: usize,
+) -> io::Result<()> {
+    execute!(stdout, Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+
+    let end = (offset + page_size).min(lines.len());
+    for line in &lines[offset..end] {
+        writeln!(stdout, "{line}\r")?;
     }
+    stdout.flush()?;
 
     Ok(())
 }
@@ -224,11 +499,15 @@ fn print_options(
 impl std::fmt::Display for Commands {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match *self {
-            Commands::Farm { verbose: _, executor: _, no_rotation: _ } => write!(f, "farm"),
+            Commands::Farm { verbose: _, executor: _, no_rotation: _, notify: _ } => {
+                write!(f, "farm")
+            }
             Commands::Wipe { farmer: _, node: _ } => write!(f, "wipe"),
-            Commands::Info => write!(f, "info"),
+            Commands::Info { output: _ } => write!(f, "info"),
             Commands::Init => write!(f, "init"),
             Commands::OpenLogs => write!(f, "open logs directory"),
+            Commands::Logs { follow: _ } => write!(f, "view logs"),
+            Commands::Completions { shell: _ } => write!(f, "completions"),
         }
     }
 }
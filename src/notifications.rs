@@ -0,0 +1,40 @@
+//! desktop notifications for long-running farming sessions
+//!
+//! farming can run unattended for hours, so the farm loop uses this module to
+//! surface the events a user would otherwise have to keep checking logs for:
+//! initial plotting finishing, and rewards being credited
+
+use notify_rust::Notification;
+use tracing::warn;
+
+/// farming lifecycle events that are worth surfacing as a desktop notification
+#[derive(Debug, Clone)]
+pub enum FarmEvent {
+    /// initial plotting has finished
+    PlottingComplete,
+    /// a block reward was credited to the farmer
+    RewardReceived {
+        /// the credited amount, already formatted for display
+        amount: String,
+    },
+}
+
+impl FarmEvent {
+    fn body(&self) -> String {
+        match self {
+            FarmEvent::PlottingComplete => "initial plotting is complete".to_string(),
+            FarmEvent::RewardReceived { amount } => format!("reward received: {amount}"),
+        }
+    }
+}
+
+/// fires a native OS notification for `event`
+///
+/// failures to show a notification (e.g. no notification daemon running) are
+/// logged and ignored, they must never abort farming
+pub fn notify(event: &FarmEvent) {
+    if let Err(error) = Notification::new().summary("Subspace Farmer").body(&event.body()).show()
+    {
+        warn!(%error, "failed to show desktop notification");
+    }
+}
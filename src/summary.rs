@@ -0,0 +1,61 @@
+//! aggregated farmer summary: reward totals, plotting progress, and instance status
+//!
+//! the farmer instance reports state transitions to the farm loop as
+//! [`SummaryEvent`]s, which the loop turns into desktop notifications; the `info`
+//! command reports a [`Summary`]
+
+use color_eyre::eyre::Report;
+use serde::Serialize;
+
+/// progress of the farmer's initial plotting pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum PlottingStatus {
+    /// still plotting, with a rough completion percentage (0-100)
+    InProgress(u8),
+    /// initial plotting has finished
+    Complete,
+}
+
+/// a single reward credited to the farmer
+#[derive(Debug, Clone, Serialize)]
+pub struct Reward {
+    /// block number the reward was credited at
+    pub block: u32,
+    /// the credited amount, already formatted for display
+    pub amount: String,
+}
+
+/// a farm-loop state transition worth reacting to (e.g. with a desktop notification)
+#[derive(Debug, Clone)]
+pub enum SummaryEvent {
+    /// initial plotting just finished
+    PlottingComplete,
+    /// a reward was just credited
+    RewardReceived(Reward),
+}
+
+/// aggregated farmer summary: reward totals, plotting progress, and instance status
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Summary {
+    /// current plotting status, `None` until the farmer reports its first update
+    pub plotting_status: Option<PlottingStatus>,
+    /// every reward credited so far, in order
+    pub rewards: Vec<Reward>,
+    /// whether the farmer/node instance is currently running
+    pub instance_running: bool,
+}
+
+impl Summary {
+    /// returns an empty summary: no plotting status, no rewards, and
+    /// `instance_running` false; the live instance state is not read
+    ///
+    /// fails with [`ConfigNotFoundError`](crate::ConfigNotFoundError) if `subspace init`
+    /// has not been run yet
+    pub async fn collect() -> Result<Self, Report> {
+        if !crate::config::config_exists()? {
+            return Err(Report::new(crate::ConfigNotFoundError));
+        }
+
+        Ok(Self::default())
+    }
+}
@@ -0,0 +1,43 @@
+//! unit tests for the CLI binary
+
+use color_eyre::eyre::Report;
+
+use crate::{
+    classify_error, AlreadyRunningError, ConfigNotFoundError, ExitCode, NodeFailureError,
+    UserCancelled,
+};
+
+#[test]
+fn classifies_user_cancelled() {
+    assert!(matches!(classify_error(&Report::new(UserCancelled)), ExitCode::UserCancelled));
+}
+
+#[test]
+fn classifies_config_not_found() {
+    assert!(matches!(
+        classify_error(&Report::new(ConfigNotFoundError)),
+        ExitCode::ConfigNotFound
+    ));
+}
+
+#[test]
+fn classifies_already_running() {
+    assert!(matches!(
+        classify_error(&Report::new(AlreadyRunningError)),
+        ExitCode::AlreadyRunning
+    ));
+}
+
+#[test]
+fn classifies_node_failure() {
+    let error = NodeFailureError("node crashed".to_string());
+    assert!(matches!(classify_error(&Report::new(error)), ExitCode::NodeFailure));
+}
+
+#[test]
+fn classifies_unclassified_as_internal() {
+    assert!(matches!(
+        classify_error(&Report::msg("something else went wrong")),
+        ExitCode::Internal
+    ));
+}
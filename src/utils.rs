@@ -0,0 +1,87 @@
+//! small standalone helpers used across the CLI: interactive prompts, and
+//! locating the log files/directory written to by the farmer and node
+
+use std::io::{IsTerminal, Write};
+use std::path::PathBuf;
+
+use color_eyre::eyre::{Context, Report};
+
+/// set to `1` to request headless operation: the arrow-key menu and the log pager
+/// then refuse to run instead of entering raw mode; prompts are not answered from
+/// env vars or piped stdin
+const NONINTERACTIVE_ENV_VAR: &str = "SUBSPACE_NONINTERACTIVE";
+
+/// whether the CLI should avoid anything that requires an interactive TTY
+///
+/// true if `SUBSPACE_NONINTERACTIVE=1` is set, or if stdin is not a terminal
+/// (e.g. running under CI, inside a container, or as a systemd service)
+pub fn is_noninteractive() -> bool {
+    std::env::var(NONINTERACTIVE_ENV_VAR).as_deref() == Ok("1") || !std::io::stdin().is_terminal()
+}
+
+/// prompts the user with `prompt`, falling back to `default` on an empty answer,
+/// and parses the answer with `parser`
+///
+/// only reachable from an interactive TTY: [`arrow_key_mode`](crate::arrow_key_mode)
+/// refuses to run in non-interactive mode before any prompt is reached, so
+/// scripting this CLI headlessly means driving `subspace farm`'s own flags
+/// directly rather than going through these prompts
+pub fn get_user_input(
+    prompt: &str,
+    default: Option<&str>,
+    parser: fn(&str) -> Result<bool, String>,
+) -> Result<bool, Report> {
+    print!("{prompt}");
+    std::io::stdout().flush()?;
+
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    let trimmed = answer.trim();
+    let answer = if trimmed.is_empty() { default.unwrap_or(trimmed) } else { trimmed };
+
+    parser(answer).map_err(Report::msg)
+}
+
+/// parses a `y`/`yes`/`n`/`no` answer (case-insensitive) into a bool
+pub fn yes_or_no_parser(answer: &str) -> Result<bool, String> {
+    match answer.to_lowercase().as_str() {
+        "y" | "yes" => Ok(true),
+        "n" | "no" => Ok(false),
+        other => Err(format!("expected y/n, got `{other}`")),
+    }
+}
+
+/// opens the log directory in the OS file explorer
+pub fn open_log_dir() -> Result<(), Report> {
+    open::that(log_dir()?).context("failed to open the log directory")
+}
+
+/// a one-line suggestion appended to error reports, pointing users at support
+pub fn support_message() -> String {
+    "if this keeps happening, please open an issue at \
+     https://github.com/ozgunozerk/subspace-cli/issues"
+        .to_string()
+}
+
+/// directory the farmer/node logs are written to
+fn log_dir() -> Result<PathBuf, Report> {
+    let mut dir =
+        dirs::data_local_dir().ok_or_else(|| Report::msg("could not determine data directory"))?;
+    dir.push("subspace-cli");
+    dir.push("logs");
+
+    Ok(dir)
+}
+
+/// paths of every farmer/node log file, in a stable (sorted) order
+pub fn log_file_paths() -> Result<Vec<PathBuf>, Report> {
+    let dir = log_dir()?;
+    let mut paths = std::fs::read_dir(&dir)
+        .with_context(|| format!("failed to read log directory {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    Ok(paths)
+}